@@ -0,0 +1,103 @@
+use actix_cors::Cors;
+use actix_web::http::header::HeaderName;
+use std::time::Duration;
+
+/// Server bind/runtime settings, loaded from the environment so the same
+/// binary can be deployed across dev/staging/prod without recompiling.
+#[derive(Clone)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub workers: usize,
+    pub log_level: String,
+    pub keep_alive_secs: u64,
+    pub cors_allowed_origins: Vec<String>,
+    pub cors_allowed_methods: Vec<String>,
+    pub cors_allowed_headers: Vec<String>,
+    pub cookie_secure: bool,
+    pub cookie_http_only: bool,
+}
+
+impl ServerConfig {
+    pub fn from_env() -> Self {
+        Self {
+            host: env_or("HOST", "0.0.0.0".to_string()),
+            port: env_parsed_or("PORT", 8080),
+            workers: env_parsed_or("WORKERS", std::thread::available_parallelism().map_or(4, |n| n.get())),
+            log_level: env_or("LOG_LEVEL", "info".to_string()),
+            keep_alive_secs: env_parsed_or("KEEP_ALIVE_SECS", 75),
+            cors_allowed_origins: env_list_or("CORS_ALLOWED_ORIGINS", vec!["http://localhost:3000".to_string()]),
+            cors_allowed_methods: env_list_or(
+                "CORS_ALLOWED_METHODS",
+                vec!["GET".to_string(), "POST".to_string()],
+            ),
+            cors_allowed_headers: env_list_or(
+                "CORS_ALLOWED_HEADERS",
+                vec!["Content-Type".to_string(), "Authorization".to_string()],
+            ),
+            cookie_secure: env_parsed_or("COOKIE_SECURE", false),
+            cookie_http_only: env_parsed_or("COOKIE_HTTP_ONLY", true),
+        }
+    }
+
+    pub fn bind_addr(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub fn keep_alive(&self) -> Duration {
+        Duration::from_secs(self.keep_alive_secs)
+    }
+
+    /// Builds the CORS layer from this config's allowlists.
+    ///
+    /// `allowed_headers` should come from a single call to
+    /// [`parse_cors_headers`] made once at startup — `HttpServer::new`'s
+    /// factory closure runs once per worker, and re-parsing (and
+    /// re-logging warnings for) `CORS_ALLOWED_HEADERS` on every one of
+    /// those calls would be wasteful and spam the logs.
+    ///
+    /// `supports_credentials` is required because the session subsystem
+    /// relies on cookies, and `fetch(..., { credentials: 'include' })`
+    /// cannot work without it.
+    pub fn build_cors(&self, allowed_headers: &[HeaderName]) -> Cors {
+        self.cors_allowed_origins
+            .iter()
+            .fold(Cors::default(), |cors, origin| cors.allowed_origin(origin))
+            .allowed_methods(self.cors_allowed_methods.iter().map(String::as_str))
+            .allowed_headers(allowed_headers.to_vec())
+            .supports_credentials()
+    }
+}
+
+/// Parses `CORS_ALLOWED_HEADERS` entries into `HeaderName`s, skipping (and
+/// reporting) any malformed entry instead of panicking in a worker thread
+/// once the server is already accepting connections.
+pub fn parse_cors_headers(raw: &[String]) -> Vec<HeaderName> {
+    raw.iter()
+        .filter_map(|h| match HeaderName::try_from(h.as_str()) {
+            Ok(name) => Some(name),
+            Err(err) => {
+                eprintln!("ignoring invalid entry in CORS_ALLOWED_HEADERS: {h:?} ({err})");
+                None
+            }
+        })
+        .collect()
+}
+
+fn env_or(key: &str, default: String) -> String {
+    std::env::var(key).unwrap_or(default)
+}
+
+fn env_parsed_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+fn env_list_or(key: &str, default: Vec<String>) -> Vec<String> {
+    match std::env::var(key) {
+        Ok(v) => v.split(',').map(|s| s.trim().to_string()).collect(),
+        Err(_) => default,
+    }
+}
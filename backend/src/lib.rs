@@ -0,0 +1,4 @@
+pub mod config;
+pub mod middleware;
+pub mod routes;
+pub mod state;
@@ -1,19 +1,85 @@
-use actix_web::{middleware::Logger, App, HttpServer};
+use actix_session::{storage::CookieSessionStore, SessionMiddleware};
+use actix_web::{
+    cookie::Key,
+    middleware::{from_fn, Compress, DefaultHeaders, Logger},
+    web, App, HttpServer,
+};
+use std::collections::HashMap;
 
-mod routes;
+use backend::{
+    config::{parse_cors_headers, ServerConfig},
+    middleware, routes,
+    state::AppState,
+};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    env_logger::init();
+    let server_config = ServerConfig::from_env();
+    env_logger::init_from_env(env_logger::Env::default().default_filter_or(&server_config.log_level));
 
-    println!("Starting Actix Web server on http://0.0.0.0:8080");
+    println!(
+        "Starting Actix Web server on http://{}",
+        server_config.bind_addr()
+    );
 
-    HttpServer::new(|| {
+    let app_state = web::Data::new(AppState::new(HashMap::new()));
+    actix_web::rt::spawn(mark_ready_after_startup(app_state.clone()));
+    let session_key = session_signing_key();
+    let workers = server_config.workers;
+    let keep_alive = server_config.keep_alive();
+    let bind_addr = server_config.bind_addr();
+    let cookie_secure = server_config.cookie_secure;
+    let cookie_http_only = server_config.cookie_http_only;
+    let cors_allowed_headers = parse_cors_headers(&server_config.cors_allowed_headers);
+
+    HttpServer::new(move || {
         App::new()
-            .wrap(Logger::default())
-            .configure(routes::configure_test_routes)
+            .app_data(app_state.clone())
+            .wrap(from_fn(middleware::request_id))
+            .wrap(Logger::new("%a \"%r\" %s %b %T request_id=%{x-request-id}o"))
+            .wrap(server_config.build_cors(&cors_allowed_headers))
+            .wrap(Compress::default())
+            .wrap(
+                DefaultHeaders::new()
+                    .add(("X-Version", env!("CARGO_PKG_VERSION")))
+                    .add(("X-Content-Type-Options", "nosniff")),
+            )
+            .wrap(
+                SessionMiddleware::builder(CookieSessionStore::default(), session_key.clone())
+                    .cookie_secure(cookie_secure)
+                    .cookie_http_only(cookie_http_only)
+                    .build(),
+            )
+            .configure(routes::configure_routes)
     })
-    .bind("0.0.0.0:8080")?
+    .bind(bind_addr)?
+    .workers(workers)
+    .keep_alive(keep_alive)
     .run()
     .await
 }
+
+/// Marks the app ready once startup has had a moment to settle, so `/ready`
+/// briefly reports `not_ready` right after boot instead of being a
+/// permanent alias for `/health`. There's no real dependency (e.g. a DB
+/// pool) to wait on yet; swap this delay out for that wait once one exists.
+async fn mark_ready_after_startup(state: web::Data<AppState>) {
+    actix_web::rt::time::sleep(std::time::Duration::from_secs(2)).await;
+    state.mark_ready();
+}
+
+/// Loads the session signing key from `SESSION_KEY` (hex-encoded) so
+/// sessions survive restarts, falling back to a freshly generated key.
+///
+/// `Key::from` panics if the byte slice is shorter than 64 bytes, so a
+/// malformed/truncated `SESSION_KEY` falls back to a generated key rather
+/// than crashing the process.
+fn session_signing_key() -> Key {
+    match std::env::var("SESSION_KEY") {
+        Ok(hex_key) => match hex::decode(hex_key) {
+            Ok(bytes) if bytes.len() >= 64 => Key::from(&bytes),
+            _ => Key::generate(),
+        },
+        Err(_) => Key::generate(),
+    }
+}
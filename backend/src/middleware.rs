@@ -0,0 +1,29 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Generates a UUID per request, stashes it in request extensions for
+/// handlers, and echoes it back on the response via the `x-request-id`
+/// header so the `Logger` format string can surface it with
+/// `%{x-request-id}o`. Must be registered *inside* (i.e. wrapped by)
+/// `Logger` so the header is already set by the time `Logger` formats the
+/// response.
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let request_id = Uuid::new_v4().to_string();
+    req.extensions_mut().insert(request_id.clone());
+
+    let mut res = next.call(req).await?;
+    res.headers_mut().insert(
+        HeaderName::from_static(REQUEST_ID_HEADER),
+        HeaderValue::from_str(&request_id).unwrap(),
+    );
+    Ok(res)
+}
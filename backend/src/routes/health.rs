@@ -0,0 +1,25 @@
+use crate::state::AppState;
+use actix_web::{get, web, HttpResponse, Result};
+use serde_json::json;
+
+/// Liveness probe: if this handler runs at all, the process is up.
+#[get("/health")]
+pub async fn health() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(json!({ "status": "ok" })))
+}
+
+/// Readiness probe: checks shared-state invariants (e.g. DB pool
+/// initialized) and returns `503` while the app isn't ready to serve
+/// traffic yet.
+#[get("/ready")]
+pub async fn ready(data: web::Data<AppState>) -> Result<HttpResponse> {
+    if data.is_ready() {
+        Ok(HttpResponse::Ok().json(json!({ "status": "ready" })))
+    } else {
+        Ok(HttpResponse::ServiceUnavailable().json(json!({ "status": "not_ready" })))
+    }
+}
+
+pub fn configure_health_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(health).service(ready);
+}
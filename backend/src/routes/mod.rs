@@ -0,0 +1,30 @@
+mod health;
+mod session;
+mod test;
+mod users;
+
+pub use health::configure_health_routes;
+pub use session::configure_session_routes;
+pub use test::configure_test_routes;
+pub use users::configure_user_routes;
+
+use actix_web::{guard, web};
+
+/// Mounts every route group under a versioned `/api/v1` prefix.
+///
+/// Health/readiness probes stay unversioned at the root since load
+/// balancers and Kubernetes expect them at a fixed path.
+///
+/// Every handler under `/api/v1` responds with JSON, so the scope carries
+/// an `Acceptable` guard requiring the client to accept `application/json`
+/// (or send no preference at all via `*/*`, which covers curl/fetch/browsers)
+/// rather than an exact-match `Accept` guard that would reject them.
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.configure(configure_health_routes).service(
+        web::scope("/api/v1")
+            .guard(guard::Acceptable::new(mime::APPLICATION_JSON).match_star_star())
+            .configure(configure_test_routes)
+            .configure(configure_session_routes)
+            .service(web::scope("/users").configure(configure_user_routes)),
+    );
+}
@@ -0,0 +1,34 @@
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Result};
+use serde_json::json;
+
+/// Increments and returns a per-session visit count.
+///
+/// Relies on the session cookie already being set by `SessionMiddleware`;
+/// if visits always come back as `1`, check that the signing key and
+/// `cookie_secure` flag match the scheme the client is being served over.
+#[get("/session/visits")]
+pub async fn session_visits(session: Session) -> Result<HttpResponse> {
+    let visits: u32 = session.get("visits")?.unwrap_or(0) + 1;
+    session.insert("visits", visits)?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "visits": visits
+    })))
+}
+
+/// Dumps every key/value currently stored in the session, for debugging.
+#[get("/session/dump")]
+pub async fn session_dump(session: Session) -> Result<HttpResponse> {
+    let entries: std::collections::HashMap<String, String> = session
+        .entries()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+
+    Ok(HttpResponse::Ok().json(json!({ "entries": entries })))
+}
+
+pub fn configure_session_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(session_visits).service(session_dump);
+}
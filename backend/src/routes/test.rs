@@ -1,14 +1,21 @@
-use actix_web::{web, HttpResponse, Result};
+use crate::state::AppState;
+use actix_web::{get, web, HttpResponse, Result};
 use serde_json::json;
 
-pub async fn test_endpoint() -> Result<HttpResponse> {
+#[get("/test")]
+pub async fn test_endpoint(data: web::Data<AppState>) -> Result<HttpResponse> {
+    let mut request_count = data.request_count.lock().unwrap();
+    *request_count += 1;
+
     Ok(HttpResponse::Ok().json(json!({
         "message": "Test endpoint is working!",
         "status": "success",
-        "timestamp": chrono::Utc::now().to_rfc3339()
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+        "request_count": *request_count,
+        "uptime_seconds": data.start_time.elapsed().as_secs()
     })))
 }
 
 pub fn configure_test_routes(cfg: &mut web::ServiceConfig) {
-    cfg.route("/test", web::get().to(test_endpoint));
+    cfg.service(test_endpoint);
 }
@@ -0,0 +1,62 @@
+use actix_web::{error, get, post, web, HttpRequest, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct User {
+    pub id: u64,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateUser {
+    pub name: String,
+    pub email: String,
+}
+
+#[get("/{id}")]
+pub async fn get_user(path: web::Path<u64>) -> Result<HttpResponse> {
+    let id = path.into_inner();
+
+    Ok(HttpResponse::Ok().json(User {
+        id,
+        name: format!("User {id}"),
+        email: format!("user{id}@example.com"),
+    }))
+}
+
+#[post("")]
+pub async fn create_user(payload: web::Json<CreateUser>) -> Result<HttpResponse> {
+    let CreateUser { name, email } = payload.into_inner();
+
+    Ok(HttpResponse::Created().json(User {
+        id: 1,
+        name,
+        email,
+    }))
+}
+
+/// Turns a malformed JSON body into a structured `400` instead of Actix's
+/// default plain-text error, so clients can branch on `error`/`field`.
+pub fn json_error_handler(err: error::JsonPayloadError, _req: &HttpRequest) -> error::Error {
+    let field = match &err {
+        error::JsonPayloadError::Deserialize(e) => format!("line {}, column {}", e.line(), e.column()),
+        _ => String::new(),
+    };
+
+    error::InternalError::from_response(
+        err.to_string(),
+        HttpResponse::BadRequest().json(json!({
+            "error": err.to_string(),
+            "field": field,
+        })),
+    )
+    .into()
+}
+
+pub fn configure_user_routes(cfg: &mut web::ServiceConfig) {
+    cfg.app_data(web::JsonConfig::default().error_handler(json_error_handler))
+        .service(get_user)
+        .service(create_user);
+}
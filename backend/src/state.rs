@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Shared application state injected into handlers via `web::Data`.
+///
+/// Constructed once in `main` and wrapped in `web::Data` so every worker
+/// thread shares the same counter, start time, and config map.
+pub struct AppState {
+    pub request_count: Mutex<u64>,
+    pub start_time: Instant,
+    pub config: HashMap<String, String>,
+    db_ready: AtomicBool,
+}
+
+impl AppState {
+    pub fn new(config: HashMap<String, String>) -> Self {
+        Self {
+            request_count: Mutex::new(0),
+            start_time: Instant::now(),
+            config,
+            db_ready: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether dependencies the readiness probe cares about (e.g. a DB
+    /// pool) have finished initializing.
+    pub fn is_ready(&self) -> bool {
+        self.db_ready.load(Ordering::Relaxed)
+    }
+
+    /// Flips the readiness flag once startup work (e.g. warming up a DB
+    /// pool) has finished. There's no real DB pool yet, so `main` calls
+    /// this after a short fixed delay; swap in the real completion signal
+    /// once one exists.
+    pub fn mark_ready(&self) {
+        self.db_ready.store(true, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,65 @@
+use actix_web::{test, web, App};
+use backend::{routes, state::AppState};
+use std::collections::HashMap;
+
+/// The `/api/v1` scope only serves clients that can accept JSON.
+#[actix_web::test]
+async fn api_v1_rejects_clients_that_cannot_accept_json() {
+    let app_state = web::Data::new(AppState::new(HashMap::new()));
+    let app = test::init_service(
+        App::new()
+            .app_data(app_state.clone())
+            .configure(routes::configure_routes),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/test")
+        .insert_header(("accept", "text/html"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 404);
+}
+
+#[actix_web::test]
+async fn api_v1_accepts_clients_that_ask_for_json_or_send_no_preference() {
+    let app_state = web::Data::new(AppState::new(HashMap::new()));
+    let app = test::init_service(
+        App::new()
+            .app_data(app_state.clone())
+            .configure(routes::configure_routes),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/test")
+        .insert_header(("accept", "application/json"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+
+    let req = test::TestRequest::get()
+        .uri("/api/v1/test")
+        .insert_header(("accept", "*/*"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+}
+
+#[actix_web::test]
+async fn health_routes_stay_outside_the_api_v1_guard() {
+    let app_state = web::Data::new(AppState::new(HashMap::new()));
+    let app = test::init_service(
+        App::new()
+            .app_data(app_state.clone())
+            .configure(routes::configure_routes),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/health")
+        .insert_header(("accept", "text/html"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+}
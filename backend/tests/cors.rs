@@ -0,0 +1,61 @@
+use actix_web::{test, App, HttpResponse};
+use backend::config::{parse_cors_headers, ServerConfig};
+
+fn test_config() -> ServerConfig {
+    ServerConfig {
+        cors_allowed_origins: vec!["https://app.example.com".to_string()],
+        cors_allowed_methods: vec!["GET".to_string()],
+        cors_allowed_headers: vec!["Content-Type".to_string()],
+        ..ServerConfig::from_env()
+    }
+}
+
+/// The CORS layer must support credentials so `fetch(..., { credentials:
+/// 'include' })` works for the cookie-based session subsystem, while still
+/// only allowing the configured origin allowlist.
+#[actix_web::test]
+async fn allowed_origin_gets_credentialed_cors_headers() {
+    let config = test_config();
+    let allowed_headers = parse_cors_headers(&config.cors_allowed_headers);
+    let app = test::init_service(
+        App::new()
+            .wrap(config.build_cors(&allowed_headers))
+            .route("/", actix_web::web::get().to(|| async { HttpResponse::Ok().finish() })),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/")
+        .insert_header(("origin", "https://app.example.com"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert_eq!(
+        res.headers().get("access-control-allow-origin").unwrap(),
+        "https://app.example.com"
+    );
+    assert_eq!(
+        res.headers().get("access-control-allow-credentials").unwrap(),
+        "true"
+    );
+}
+
+#[actix_web::test]
+async fn disallowed_origin_gets_no_cors_headers() {
+    let config = test_config();
+    let allowed_headers = parse_cors_headers(&config.cors_allowed_headers);
+    let app = test::init_service(
+        App::new()
+            .wrap(config.build_cors(&allowed_headers))
+            .route("/", actix_web::web::get().to(|| async { HttpResponse::Ok().finish() })),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/")
+        .insert_header(("origin", "https://evil.example.com"))
+        .to_request();
+    let res = test::call_service(&app, req).await;
+
+    assert!(res.headers().get("access-control-allow-origin").is_none());
+}
@@ -0,0 +1,26 @@
+use actix_web::{test, web, App};
+use backend::{routes::configure_health_routes, state::AppState};
+use std::collections::HashMap;
+
+/// `/ready` should reflect `AppState::is_ready` rather than being a
+/// permanent alias for `/health`.
+#[actix_web::test]
+async fn ready_reports_503_until_marked_ready() {
+    let app_state = web::Data::new(AppState::new(HashMap::new()));
+    let app = test::init_service(
+        App::new()
+            .app_data(app_state.clone())
+            .configure(configure_health_routes),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/ready").to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 503);
+
+    app_state.mark_ready();
+
+    let req = test::TestRequest::get().uri("/ready").to_request();
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 200);
+}
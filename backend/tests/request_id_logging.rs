@@ -0,0 +1,64 @@
+use actix_web::middleware::{from_fn, Logger};
+use actix_web::{test, web, App, HttpResponse};
+use backend::middleware::request_id;
+use std::sync::{Mutex, OnceLock};
+
+static LOG_LINES: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+fn log_lines() -> &'static Mutex<Vec<String>> {
+    LOG_LINES.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+struct CapturingLogger;
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        log_lines().lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+async fn ping() -> HttpResponse {
+    HttpResponse::Ok().finish()
+}
+
+/// Guards against the `request_id` middleware and `Logger`'s format
+/// string drifting apart again: `Logger` must be wrapped around
+/// `request_id` so the `x-request-id` response header it sets is already
+/// present by the time `Logger` formats the access log line.
+#[actix_web::test]
+async fn access_log_line_carries_the_request_id() {
+    let _ = log::set_boxed_logger(Box::new(CapturingLogger));
+    log::set_max_level(log::LevelFilter::Info);
+
+    let app = test::init_service(
+        App::new()
+            .wrap(from_fn(request_id))
+            .wrap(Logger::new("%s request_id=%{x-request-id}o"))
+            .route("/ping", web::get().to(ping)),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/ping").to_request();
+    test::call_service(&app, req).await;
+
+    let lines = log_lines().lock().unwrap();
+    let line = lines
+        .iter()
+        .rev()
+        .find(|line| line.contains("request_id="))
+        .expect("expected an access log line containing request_id=");
+
+    let logged_id = line
+        .split("request_id=")
+        .nth(1)
+        .expect("log line should contain request_id=");
+
+    uuid::Uuid::parse_str(logged_id)
+        .unwrap_or_else(|err| panic!("expected a UUID in the log line, got {logged_id:?}: {err}"));
+}
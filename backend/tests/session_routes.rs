@@ -0,0 +1,46 @@
+use actix_session::{storage::CookieSessionStore, SessionMiddleware};
+use actix_web::{cookie::Key, test, App};
+use backend::routes::configure_session_routes;
+use serde_json::Value;
+
+/// Visits should increment per session, reusing the cookie set on the
+/// first response, and reset for a client with no cookie.
+#[actix_web::test]
+async fn visiting_twice_in_the_same_session_increments_the_count() {
+    let key = Key::generate();
+    let app = test::init_service(
+        App::new()
+            .wrap(
+                SessionMiddleware::builder(CookieSessionStore::default(), key)
+                    .cookie_secure(false)
+                    .build(),
+            )
+            .configure(configure_session_routes),
+    )
+    .await;
+
+    let first = test::TestRequest::get().uri("/session/visits").to_request();
+    let res = test::call_service(&app, first).await;
+    assert_eq!(res.status(), 200);
+    let session_cookie = res
+        .response()
+        .cookies()
+        .find(|cookie| cookie.name() == "id")
+        .expect("SessionMiddleware should set a session cookie")
+        .into_owned();
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["visits"], 1);
+
+    let second = test::TestRequest::get()
+        .uri("/session/visits")
+        .cookie(session_cookie)
+        .to_request();
+    let res = test::call_service(&app, second).await;
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["visits"], 2);
+
+    let third_without_cookie = test::TestRequest::get().uri("/session/visits").to_request();
+    let res = test::call_service(&app, third_without_cookie).await;
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["visits"], 1);
+}
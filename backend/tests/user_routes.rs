@@ -0,0 +1,44 @@
+use actix_web::{test, web, App};
+use backend::routes::configure_user_routes;
+use serde_json::{json, Value};
+
+/// `json_error_handler` should turn a malformed JSON body into a structured
+/// `400` with `error`/`field` fields, not Actix's default plain-text error.
+#[actix_web::test]
+async fn malformed_json_body_returns_structured_400() {
+    let app =
+        test::init_service(App::new().service(web::scope("/users").configure(configure_user_routes)))
+            .await;
+
+    let req = test::TestRequest::post()
+        .uri("/users")
+        .insert_header(("content-type", "application/json"))
+        .set_payload("{not valid json")
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 400);
+
+    let body: Value = test::read_body_json(res).await;
+    assert!(!body["error"].as_str().unwrap().is_empty());
+    assert!(body["field"].as_str().unwrap().contains("line"));
+}
+
+#[actix_web::test]
+async fn valid_json_body_creates_a_user() {
+    let app =
+        test::init_service(App::new().service(web::scope("/users").configure(configure_user_routes)))
+            .await;
+
+    let req = test::TestRequest::post()
+        .uri("/users")
+        .set_json(json!({ "name": "Ada", "email": "ada@example.com" }))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), 201);
+
+    let body: Value = test::read_body_json(res).await;
+    assert_eq!(body["name"], "Ada");
+    assert_eq!(body["email"], "ada@example.com");
+}